@@ -3,24 +3,285 @@
 
 #![deny(warnings)]
 
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 use bytes::{Bytes, BytesMut};
 use futures::channel::mpsc::{channel as MpscChannel, Receiver, Sender as MpscSender};
-use futures::stream::StreamExt;
+use futures::stream::{FuturesUnordered, StreamExt};
 use futures::SinkExt;
 
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
+use rdkafka::config::ClientConfig;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use serde::Serialize;
 use std::io;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use sui_core::authority::*;
 use sui_core::authority_server::AuthorityServer;
 use sui_network::network::{NetworkClient, NetworkServer};
 use sui_network::transport;
+use sui_types::base_types::{ObjectDigest, ObjectID, SequenceNumber};
+use sui_types::crypto::{get_key_pair_from_rng, Signature};
 use sui_types::{messages::*, serialize::*};
 use tokio::sync::Notify;
 use tokio::time;
-use tracing::{error, info};
+use tracing::{debug, error, info, warn};
+
+/// Bucket boundaries grow geometrically by this ratio, giving roughly 10%
+/// resolution per bucket.
+const HISTOGRAM_BUCKET_RATIO: f64 = 1.1;
+/// Smallest latency (in microseconds) the histogram distinguishes.
+const HISTOGRAM_MIN_US: f64 = 10.0;
+/// Largest latency (in microseconds) the histogram distinguishes; anything
+/// above this is folded into the top bucket.
+const HISTOGRAM_MAX_US: f64 = 10_000_000.0;
+
+fn histogram_num_buckets() -> usize {
+    ((HISTOGRAM_MAX_US / HISTOGRAM_MIN_US).ln() / HISTOGRAM_BUCKET_RATIO.ln()).ceil() as usize
+}
+
+fn histogram_bucket_for(value_us: u128) -> usize {
+    let v = (value_us as f64).max(HISTOGRAM_MIN_US);
+    let idx = (v / HISTOGRAM_MIN_US).ln() / HISTOGRAM_BUCKET_RATIO.ln();
+    (idx.floor().max(0.0) as usize).min(histogram_num_buckets() - 1)
+}
+
+fn histogram_bucket_upper_bound_us(idx: usize) -> u128 {
+    (HISTOGRAM_MIN_US * HISTOGRAM_BUCKET_RATIO.powi(idx as i32 + 1)) as u128
+}
+
+/// A logarithmically-bucketed latency histogram, recorded in microseconds.
+///
+/// Bucket boundaries are powers of [`HISTOGRAM_BUCKET_RATIO`] between
+/// [`HISTOGRAM_MIN_US`] and [`HISTOGRAM_MAX_US`], giving fine resolution on
+/// short round-trips while still covering multi-second tail latencies.
+/// Histograms merge bucket-wise in O(buckets), so each worker task can keep
+/// a local one and fold it into a run-wide total without shipping every
+/// individual sample over a channel.
+#[derive(Clone, Debug)]
+pub struct LatencyHistogram {
+    buckets: Vec<u64>,
+    count: u64,
+    sum_us: u128,
+    min_us: u128,
+    max_us: u128,
+}
+
+impl LatencyHistogram {
+    pub fn new() -> Self {
+        Self {
+            buckets: vec![0; histogram_num_buckets()],
+            count: 0,
+            sum_us: 0,
+            min_us: u128::MAX,
+            max_us: 0,
+        }
+    }
+
+    pub fn record(&mut self, value_us: u128) {
+        self.buckets[histogram_bucket_for(value_us)] += 1;
+        self.count += 1;
+        self.sum_us += value_us;
+        self.min_us = self.min_us.min(value_us);
+        self.max_us = self.max_us.max(value_us);
+    }
+
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        for (a, b) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            *a += b;
+        }
+        self.count += other.count;
+        self.sum_us += other.sum_us;
+        self.min_us = self.min_us.min(other.min_us);
+        self.max_us = self.max_us.max(other.max_us);
+    }
+
+    /// Returns the smallest bucket upper bound at or above the `p`th
+    /// percentile (`p` in `[0, 100]`).
+    pub fn percentile(&self, p: f64) -> u128 {
+        if self.count == 0 {
+            return 0;
+        }
+        let target = ((p / 100.0) * self.count as f64).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (idx, &c) in self.buckets.iter().enumerate() {
+            cumulative += c;
+            if cumulative >= target {
+                return histogram_bucket_upper_bound_us(idx);
+            }
+        }
+        self.max_us
+    }
+
+    pub fn stats(&self) -> PhaseStats {
+        PhaseStats {
+            count: self.count,
+            min_us: if self.count == 0 { 0 } else { self.min_us },
+            max_us: self.max_us,
+            mean_us: if self.count == 0 {
+                0.0
+            } else {
+                self.sum_us as f64 / self.count as f64
+            },
+            p50_us: self.percentile(50.0),
+            p90_us: self.percentile(90.0),
+            p99_us: self.percentile(99.0),
+            p99_9_us: self.percentile(99.9),
+        }
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Percentile summary derived from a [`LatencyHistogram`], in microseconds.
+#[derive(Clone, Debug, Default)]
+pub struct PhaseStats {
+    pub count: u64,
+    pub min_us: u128,
+    pub max_us: u128,
+    pub mean_us: f64,
+    pub p50_us: u128,
+    pub p90_us: u128,
+    pub p99_us: u128,
+    pub p99_9_us: u128,
+}
+
+/// Latency report for a benchmark run, broken down by the order and
+/// confirmation phases of the quorum protocol.
+#[derive(Clone, Debug, Default)]
+pub struct LatencyReport {
+    pub order: PhaseStats,
+    pub confirmation: PhaseStats,
+}
+
+/// Per-round latency samples, owned by a single `send_tx_for_quorum` task and
+/// folded into the run-wide [`LatencyReport`] by `start()`.
+#[derive(Clone, Debug, Default)]
+struct RoundHistograms {
+    order: LatencyHistogram,
+    confirmation: LatencyHistogram,
+}
+
+/// A single completed quorum round, shaped for export to an external
+/// analytics pipeline rather than in-process aggregation.
+#[derive(Clone, Debug, Serialize)]
+pub struct RoundRecord {
+    pub tx_digest: String,
+    pub order_us: u128,
+    pub confirmation_us: u128,
+    pub validators_reached: usize,
+    pub timestamp_ms: u128,
+}
+
+/// Destination for per-round benchmark results, in addition to the
+/// in-memory histograms `start()` always collects. Implementations must be
+/// safe to call from every worker task concurrently.
+pub trait ResultsSink: Send + Sync {
+    fn record(&self, record: RoundRecord);
+}
+
+/// Connection settings for [`KafkaResultsSink`].
+#[derive(Clone, Debug)]
+pub struct KafkaSinkConfig {
+    pub brokers: String,
+    pub topic: String,
+    pub client_id: String,
+    /// Capacity of the bounded channel between worker tasks and the Kafka
+    /// producer task; once full, `record` drops samples rather than
+    /// blocking transaction sending.
+    pub buffer_size: usize,
+}
+
+/// A [`ResultsSink`] that streams each round's record to Kafka as JSON.
+///
+/// Worker tasks never talk to `rdkafka` directly: `record` pushes onto a
+/// bounded channel drained by a single producer task, so a slow or
+/// backpressured broker can only ever delay that channel, never the
+/// transaction-sending path.
+pub struct KafkaResultsSink {
+    // A single shared sender: a freshly cloned sender always has a
+    // guaranteed slot of its own, so `try_send` on a clone never sees the
+    // channel as full.
+    sender: std::sync::Mutex<MpscSender<RoundRecord>>,
+}
+
+impl KafkaResultsSink {
+    pub fn new(config: KafkaSinkConfig) -> Self {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", &config.brokers)
+            .set("client.id", &config.client_id)
+            .create()
+            .expect("failed to create Kafka producer");
+
+        let (sender, receiver) = MpscChannel(config.buffer_size);
+        tokio::spawn(kafka_producer_task(producer, config.topic, receiver));
+
+        Self {
+            sender: std::sync::Mutex::new(sender),
+        }
+    }
+}
+
+impl ResultsSink for KafkaResultsSink {
+    fn record(&self, record: RoundRecord) {
+        if let Err(e) = self.sender.lock().unwrap().try_send(record) {
+            error!("Kafka results sink buffer full, dropping record: {:?}", e);
+        }
+    }
+}
+
+async fn kafka_producer_task(
+    producer: FutureProducer,
+    topic: String,
+    mut receiver: Receiver<RoundRecord>,
+) {
+    while let Some(record) = receiver.next().await {
+        let payload = match serde_json::to_string(&record) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!("Failed to serialize benchmark record: {:?}", e);
+                continue;
+            }
+        };
+
+        let send_result = producer
+            .send(
+                FutureRecord::to(&topic)
+                    .payload(&payload)
+                    .key(&record.tx_digest),
+                Duration::from_secs(5),
+            )
+            .await;
+        if let Err((e, _)) = send_result {
+            error!("Failed to send benchmark record to Kafka: {:?}", e);
+        }
+    }
+}
+
+fn round_digest(order_chunk: &[Bytes]) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    for tx in order_chunk {
+        tx.hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
 
 pub fn check_transaction_response(reply_message: Result<SerializedMessage, Error>) {
     match reply_message {
@@ -56,117 +317,427 @@ pub async fn send_tx_chunks(
     (elapsed, tx_resp)
 }
 
+/// A minimal non-empty payload used for connectivity probes. A zero-chunk
+/// `send_tx_chunks` call lets `batch_send` short-circuit on an empty input
+/// without ever dialing out, which would make every probe trivially
+/// succeed regardless of real connectivity; one real chunk forces an
+/// actual round-trip attempt.
+const CONNECTIVITY_PROBE_PAYLOAD: &[u8] = &[0u8];
+
+/// Periodically probes each validator's `NetworkClient` and tracks whether it
+/// is currently reachable, so `send_tx_for_quorum` can skip known-dead
+/// validators instead of waiting out their send timeout every round.
+///
+/// A probe is a single-chunk `send_tx_chunks` call under a short timeout.
+/// `NetworkClient` holds no persistent socket of its own — it is cloned
+/// freely throughout this file for concurrent fan-out, and every
+/// `batch_send` dials a fresh transport connection rather than reusing a
+/// live one — so there is no connection object here to explicitly tear
+/// down. A failed probe is retried once immediately, which is this type's
+/// equivalent of tearing down and re-establishing the connection, before
+/// the validator is marked unreachable.
+pub struct ConnectivityMonitor {
+    net_clients: Vec<NetworkClient>,
+    statuses: Vec<Arc<AtomicBool>>,
+    probe_interval: Duration,
+    probe_timeout: Duration,
+}
+
+impl ConnectivityMonitor {
+    pub fn new(net_clients: Vec<NetworkClient>, probe_interval: Duration) -> Self {
+        let statuses = net_clients
+            .iter()
+            .map(|_| Arc::new(AtomicBool::new(true)))
+            .collect();
+        Self {
+            net_clients,
+            statuses,
+            probe_interval,
+            probe_timeout: Duration::from_secs(5),
+        }
+    }
+
+    pub fn is_reachable(&self, idx: usize) -> bool {
+        self.statuses[idx].load(Ordering::SeqCst)
+    }
+
+    pub fn reachable_count(&self) -> usize {
+        self.statuses
+            .iter()
+            .filter(|s| s.load(Ordering::SeqCst))
+            .count()
+    }
+
+    /// Runs the probe loop until dropped. Intended to be `tokio::spawn`ed
+    /// alongside the generator it backs.
+    pub async fn run(self: Arc<Self>) {
+        let mut ticker = time::interval(self.probe_interval);
+        let quorum_size = 2 * self.net_clients.len().saturating_sub(1) / 3 + 1;
+        loop {
+            ticker.tick().await;
+
+            for (idx, client) in self.net_clients.iter().enumerate() {
+                let client = client.clone();
+                let status = self.statuses[idx].clone();
+                let probe_timeout = self.probe_timeout;
+                tokio::spawn(async move {
+                    let probe = || vec![Bytes::from_static(CONNECTIVITY_PROBE_PAYLOAD)];
+                    let mut ok = time::timeout(
+                        probe_timeout,
+                        send_tx_chunks(probe(), client.clone(), 1),
+                    )
+                    .await
+                    .is_ok();
+
+                    if !ok {
+                        // Tear down and re-establish: dial once more before
+                        // giving up, so a transient blip doesn't take the
+                        // validator out of rotation for a full
+                        // `probe_interval` on the strength of one failed
+                        // attempt.
+                        ok = time::timeout(probe_timeout, send_tx_chunks(probe(), client, 1))
+                            .await
+                            .is_ok();
+                    }
+
+                    let was_reachable = status.swap(ok, Ordering::SeqCst);
+                    if was_reachable && !ok {
+                        warn!("Validator connection probe failed after reconnect attempt, marking unreachable");
+                    } else if !was_reachable && ok {
+                        info!("Validator connection re-established, marking reachable again");
+                    }
+                });
+            }
+
+            if self.reachable_count() < quorum_size {
+                warn!(
+                    "Reachable validator set ({}) has dropped below quorum ({})",
+                    self.reachable_count(),
+                    quorum_size
+                );
+            }
+        }
+    }
+}
+
+/// Shared counters that worker tasks update as rounds complete, sampled
+/// periodically by a [`StatusReporter`] so a run's progress is visible
+/// before `start()` returns.
+#[derive(Default)]
+pub struct RunStats {
+    rounds_completed: AtomicU64,
+    orders_reached_quorum: AtomicU64,
+    confirmations_reached_quorum: AtomicU64,
+}
+
+impl RunStats {
+    fn record_round(&self, order_quorum_met: bool, confirmation_quorum_met: bool) {
+        self.rounds_completed.fetch_add(1, Ordering::Relaxed);
+        if order_quorum_met {
+            self.orders_reached_quorum.fetch_add(1, Ordering::Relaxed);
+        }
+        if confirmation_quorum_met {
+            self.confirmations_reached_quorum.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// A point-in-time sample of [`RunStats`], plus the round rate observed over
+/// the reporting interval that produced it.
+///
+/// This is rounds per second, not transactions per second: a round is one
+/// completed `send_tx_for_quorum`/`send_tx_chunks_notif` task, and each one
+/// can cover more than one transaction once `chunk_size_per_task` is above
+/// its minimum, so the two rates diverge by that (now user-configurable)
+/// factor.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RunStatusSnapshot {
+    pub rounds_completed: u64,
+    pub orders_reached_quorum: u64,
+    pub confirmations_reached_quorum: u64,
+    pub interval_rounds_per_sec: f64,
+}
+
+/// Destination for periodic [`RunStatusSnapshot`]s. A run can drive several
+/// of these off the same sample (e.g. a console informant alongside a
+/// Kafka-backed one).
+pub trait StatusSink: Send + Sync {
+    fn report(&self, snapshot: &RunStatusSnapshot);
+}
+
+/// Default [`StatusSink`]: logs a one-line progress update via
+/// `tracing::info!`.
+pub struct TracingStatusSink;
+
+impl StatusSink for TracingStatusSink {
+    fn report(&self, snapshot: &RunStatusSnapshot) {
+        info!(
+            "rounds={} orders_at_quorum={} confirmations_at_quorum={} round_rate={:.1} rounds/s",
+            snapshot.rounds_completed,
+            snapshot.orders_reached_quorum,
+            snapshot.confirmations_reached_quorum,
+            snapshot.interval_rounds_per_sec,
+        );
+    }
+}
+
+/// A [`StatusSink`] that streams each [`RunStatusSnapshot`] to Kafka as
+/// JSON, following the same bounded-channel-to-a-single-producer-task shape
+/// as [`KafkaResultsSink`].
+pub struct KafkaStatusSink {
+    sender: std::sync::Mutex<MpscSender<RunStatusSnapshot>>,
+}
+
+impl KafkaStatusSink {
+    pub fn new(config: KafkaSinkConfig) -> Self {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", &config.brokers)
+            .set("client.id", &config.client_id)
+            .create()
+            .expect("failed to create Kafka producer");
+
+        let (sender, receiver) = MpscChannel(config.buffer_size);
+        tokio::spawn(kafka_status_producer_task(producer, config.topic, receiver));
+
+        Self {
+            sender: std::sync::Mutex::new(sender),
+        }
+    }
+}
+
+impl StatusSink for KafkaStatusSink {
+    fn report(&self, snapshot: &RunStatusSnapshot) {
+        if let Err(e) = self.sender.lock().unwrap().try_send(snapshot.clone()) {
+            error!("Kafka status sink buffer full, dropping snapshot: {:?}", e);
+        }
+    }
+}
+
+async fn kafka_status_producer_task(
+    producer: FutureProducer,
+    topic: String,
+    mut receiver: Receiver<RunStatusSnapshot>,
+) {
+    while let Some(snapshot) = receiver.next().await {
+        let payload = match serde_json::to_string(&snapshot) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!("Failed to serialize status snapshot: {:?}", e);
+                continue;
+            }
+        };
+
+        let send_result = producer
+            .send(
+                FutureRecord::to(&topic).payload(&payload).key(""),
+                Duration::from_secs(5),
+            )
+            .await;
+        if let Err((e, _)) = send_result {
+            error!("Failed to send status snapshot to Kafka: {:?}", e);
+        }
+    }
+}
+
+/// Periodically samples a [`RunStats`] and fans the resulting
+/// [`RunStatusSnapshot`] out to every configured [`StatusSink`]. Shuts down
+/// once `shutdown` is set, which `start()` does as soon as its main loop
+/// exits.
+struct StatusReporter {
+    stats: Arc<RunStats>,
+    sinks: Vec<Arc<dyn StatusSink>>,
+    display_interval: Duration,
+}
+
+impl StatusReporter {
+    async fn run(self, shutdown: Arc<AtomicBool>) {
+        let mut ticker = time::interval(self.display_interval);
+        let mut last_rounds = 0u64;
+        loop {
+            ticker.tick().await;
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let rounds = self.stats.rounds_completed.load(Ordering::Relaxed);
+            let interval_rounds_per_sec = calculate_throughput(
+                rounds.saturating_sub(last_rounds) as usize,
+                self.display_interval.as_micros(),
+            );
+            last_rounds = rounds;
+
+            let snapshot = RunStatusSnapshot {
+                rounds_completed: rounds,
+                orders_reached_quorum: self.stats.orders_reached_quorum.load(Ordering::Relaxed),
+                confirmations_reached_quorum: self
+                    .stats
+                    .confirmations_reached_quorum
+                    .load(Ordering::Relaxed),
+                interval_rounds_per_sec,
+            };
+            for sink in &self.sinks {
+                sink.report(&snapshot);
+            }
+        }
+    }
+}
+
+/// Drives one quorum phase (order or confirmation) to completion against all
+/// `net_clients`.
+///
+/// Every validator send is polled concurrently through a single
+/// `FuturesUnordered` rather than one `tokio::spawn`ed task per validator.
+/// As soon as `2f+1` replies have arrived the remaining in-flight sends are
+/// dropped, so a slow or wedged validator can no longer hold the round open
+/// past quorum. Returns the phase's elapsed time, the number of validators
+/// that replied in time, and whether that number reached `2f+1`.
+async fn run_quorum_phase(
+    net_clients: &[NetworkClient],
+    chunk: &[Bytes],
+    conn: usize,
+    connectivity: Option<&Arc<ConnectivityMonitor>>,
+    phase_timeout: Duration,
+) -> (u128, usize, bool) {
+    let num_validators = net_clients.len();
+    let quorum_size = 2 * (num_validators - 1) / 3 + 1;
+
+    let mut sends: FuturesUnordered<_> = net_clients
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| connectivity.map_or(true, |c| c.is_reachable(*idx)))
+        .map(|(_, client)| send_tx_chunks(chunk.to_vec(), client.clone(), conn))
+        .collect();
+
+    let time_start = Instant::now();
+    let deadline = time::Instant::now() + phase_timeout;
+    let mut reached = 0;
+    while reached < quorum_size {
+        match time::timeout_at(deadline, sends.next()).await {
+            Ok(Some((_, responses))) => {
+                reached += 1;
+                let _: Vec<_> = responses
+                    .par_iter()
+                    .filter_map(|r| r.as_ref().ok())
+                    .map(|bytes| check_transaction_response(deserialize_message(&bytes[..])))
+                    .collect();
+            }
+            // Either every send has completed or the phase timeout
+            // elapsed; either way quorum was not reached in time.
+            Ok(None) | Err(_) => break,
+        }
+    }
+    // Remaining in-flight sends (and their TCP connections) are dropped here.
+
+    (time_start.elapsed().as_micros(), reached, reached >= quorum_size)
+}
+
 /// TODO: Add support for stake
 async fn send_tx_for_quorum(
     notif: Arc<Notify>,
     order_chunk: Vec<Bytes>,
     conf_chunk: Vec<Bytes>,
 
-    result_chann_tx: &mut MpscSender<u128>,
+    result_chann_tx: &mut MpscSender<RoundHistograms>,
     net_clients: Vec<NetworkClient>,
     conn: usize,
+    results_sink: Option<Arc<dyn ResultsSink>>,
+    connectivity: Option<Arc<ConnectivityMonitor>>,
+    stats: Arc<RunStats>,
+    phase_timeout: Duration,
+    shutdown: Arc<AtomicBool>,
 ) {
-    let num_validators = net_clients.len();
-    // For receiving info back from the subtasks
-    let (order_chann_tx, mut order_chann_rx) = MpscChannel(net_clients.len() * 2);
-
-    // Send intent orders to 3f+1
-    let order_start_notifier = Arc::new(Notify::new());
-    for n in net_clients.clone() {
-        // This is for sending a start signal to the subtasks
-        let notif = order_start_notifier.clone();
-        // This is for getting the elapsed time
-        let mut ch_tx = order_chann_tx.clone();
-        // Chunk to send for order_
-        let chunk = order_chunk.clone();
+    // Wait for tick
+    notif.notified().await;
 
-        tokio::spawn(async move {
-            send_tx_chunks_notif(notif, chunk, &mut ch_tx, n.clone(), conn).await;
-            println!("Spawn for order {:?}", n);
-        });
+    // A shutdown may have woken every still-parked worker at once via
+    // `notify_waiters` without this chunk ever being assigned a real tick;
+    // bail out instead of dispatching a round that was never scheduled.
+    if shutdown.load(Ordering::SeqCst) {
+        return;
     }
-    drop(order_chann_tx);
 
-    // Wait for tick
-    notif.notified().await;
-    // Notify all the subtasks
-    order_start_notifier.notify_waiters();
-    let time_start = Instant::now();
+    let (order_us, order_reached, order_quorum_met) = run_quorum_phase(
+        &net_clients,
+        &order_chunk,
+        conn,
+        connectivity.as_ref(),
+        phase_timeout,
+    )
+    .await;
+    debug!("order phase reached {} validators", order_reached);
 
-    // Wait for 2f+1
-    let mut count = 0;
+    let (conf_us, conf_reached, conf_quorum_met) = run_quorum_phase(
+        &net_clients,
+        &conf_chunk,
+        conn,
+        connectivity.as_ref(),
+        phase_timeout,
+    )
+    .await;
+    debug!("confirmation phase reached {} validators", conf_reached);
 
-    while time::timeout(Duration::from_secs(10), order_chann_rx.next())
-        .await
-        .unwrap_or(None)
-        .is_some()
-    {
-        count += 1;
-
-        if count > 2 * (num_validators - 1) / 3 {
-            break;
-        }
-    }
-    println!("order {}", count);
-    // Confirmation step
-    let (conf_chann_tx, mut conf_chann_rx) = MpscChannel(net_clients.len() * 2);
-
-    // Send the confs
-    let mut handles = vec![];
-    for n in net_clients {
-        let chunk = conf_chunk.clone();
-        let mut chann_tx = conf_chann_tx.clone();
-        handles.push(tokio::spawn(async move {
-            let r = send_tx_chunks(chunk, n.clone(), conn).await;
-            println!("Spawn for conf {:?}", n);
-            match chann_tx.send(r.0).await {
-                Ok(_) => (),
-                Err(e) => if !e.is_disconnected() {
-                    panic!("Send failed! {:?}", n)
-                }
-            }
+    stats.record_round(order_quorum_met, conf_quorum_met);
 
-            let _: Vec<_> =
-                r.1.par_iter()
-                    .map(|q| {
-                        check_transaction_response(deserialize_message(&(q.as_ref().unwrap())[..]))
-                    })
-                    .collect();
-        }));
-    }
-    drop(conf_chann_tx);
+    let mut histograms = RoundHistograms::default();
+    histograms.order.record(order_us);
+    histograms.confirmation.record(conf_us);
 
-    // Reset counter
-    count = 0;
-    while time::timeout(Duration::from_secs(10), conf_chann_rx.next())
-        .await
-        .unwrap_or(None)
-        .is_some()
-    {
-        count += 1;
+    if let Some(sink) = results_sink {
+        sink.record(RoundRecord {
+            tx_digest: round_digest(&order_chunk),
+            order_us,
+            confirmation_us: conf_us,
+            validators_reached: conf_reached,
+            timestamp_ms: now_millis(),
+        });
+    }
 
-        if count > 2 * (num_validators - 1) / 3 {
-            break;
+    // Send the merged per-round histograms over; if the receiver has
+    // already gone away (e.g. the run was cancelled) there is nothing left
+    // to report to, so stop cleanly instead of panicking.
+    if let Err(e) = result_chann_tx.send(histograms).await {
+        if !e.is_disconnected() {
+            panic!("Send failed for quorum round");
         }
     }
-    println!("conf {}", count);
-
-    let elapsed = time_start.elapsed().as_micros();
-
-    // Send the total time over
-    result_chann_tx.send(elapsed).await.unwrap();
 }
 
 async fn send_tx_chunks_notif(
     notif: Arc<Notify>,
     tx_chunk: Vec<Bytes>,
-    result_chann_tx: &mut MpscSender<u128>,
+    result_chann_tx: &mut MpscSender<RoundHistograms>,
     net_client: NetworkClient,
     conn: usize,
+    results_sink: Option<Arc<dyn ResultsSink>>,
+    stats: Arc<RunStats>,
+    shutdown: Arc<AtomicBool>,
 ) {
     notif.notified().await;
-    let r = send_tx_chunks(tx_chunk, net_client.clone(), conn).await;
-    match result_chann_tx.send(r.0).await {
+
+    // See the equivalent check in `send_tx_for_quorum`.
+    if shutdown.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let r = send_tx_chunks(tx_chunk.clone(), net_client.clone(), conn).await;
+    let mut histograms = RoundHistograms::default();
+    histograms.order.record(r.0);
+
+    // There is only one validator in this path, so "quorum" is trivially
+    // met by a single successful send.
+    stats.record_round(true, false);
+
+    if let Some(sink) = results_sink {
+        sink.record(RoundRecord {
+            tx_digest: round_digest(&tx_chunk),
+            order_us: r.0,
+            confirmation_us: 0,
+            validators_reached: 1,
+            timestamp_ms: now_millis(),
+        });
+    }
+
+    match result_chann_tx.send(histograms).await {
         Ok(_) => (),
         Err(e) => if !e.is_disconnected() {
             panic!("Send failed! {:?}", net_client)
@@ -179,6 +750,64 @@ async fn send_tx_chunks_notif(
             .collect();
 }
 
+/// Tunables for a generator's buffering and payload limits, broken out of
+/// what used to be the implicit `conn * 2` chunk size, `transactions.len()
+/// * 2` channel capacity, and hardcoded 10s per-phase timeout. Letting
+/// these vary independently makes it possible to tune buffering for a
+/// particular validator configuration or reproduce large-payload stress
+/// scenarios.
+#[derive(Clone, Debug)]
+pub struct LoadGenConfig {
+    /// Transactions larger than this are rejected at construction rather
+    /// than dispatched.
+    pub max_payload_size: usize,
+    /// Number of transaction entries (order+confirmation interleaved, so
+    /// this must be even) handed to each worker task per tick.
+    pub chunk_size_per_task: usize,
+    /// Capacity of the bounded channel worker tasks report completed
+    /// rounds over, set independently of the transaction count. Each worker
+    /// task holds its own sender, and each sender adds one guaranteed slot
+    /// on top of this capacity, so the channel can hold up to this many
+    /// messages plus one per worker task.
+    pub channel_capacity: usize,
+    /// How long a single order or confirmation phase waits for `2f+1`
+    /// replies before giving up on the round.
+    pub phase_timeout: Duration,
+}
+
+impl LoadGenConfig {
+    fn validate(&self) {
+        assert!(
+            self.max_payload_size > 0,
+            "max_payload_size must be greater than zero"
+        );
+        assert!(
+            self.chunk_size_per_task > 0,
+            "chunk_size_per_task must be greater than zero"
+        );
+        assert!(
+            self.chunk_size_per_task % 2 == 0,
+            "chunk_size_per_task must be even: each worker task's chunk is \
+             split into an order half and a confirmation half"
+        );
+        assert!(
+            self.channel_capacity > 0,
+            "channel_capacity must be greater than zero"
+        );
+    }
+}
+
+impl Default for LoadGenConfig {
+    fn default() -> Self {
+        Self {
+            max_payload_size: 1024 * 1024,
+            chunk_size_per_task: 2,
+            channel_capacity: 1000,
+            phase_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
 pub struct FixedRateLoadGenerator {
     /// The time between sending transactions chunks
     /// Anything below 10ms causes degradation in resolution
@@ -193,11 +822,38 @@ pub struct FixedRateLoadGenerator {
 
     pub transactions: Vec<Bytes>,
 
-    pub results_chann_rx: Receiver<u128>,
+    results_chann_rx: Receiver<RoundHistograms>,
 
-    /// This is the chunk size actually assigned for each tick per task
-    /// It is 2*chunk_size due to order and confirmation steps
+    /// The number of transaction entries (order and confirmation
+    /// interleaved, so twice the number of pairs) assigned to each worker
+    /// task per tick. Set from [`LoadGenConfig::chunk_size_per_task`].
     pub chunk_size_per_task: usize,
+
+    /// Set once a shutdown has been requested (Ctrl-C, or `request_shutdown`).
+    /// Worker tasks still parked on `tick_notifier` check this as soon as
+    /// they wake and return without dispatching a round.
+    shutdown: Arc<AtomicBool>,
+
+    /// Notified by `request_shutdown` (and the Ctrl-C handler) so
+    /// `start()`'s tick loop reacts to a shutdown immediately instead of
+    /// waiting for the next `interval.tick()`.
+    shutdown_notify: Arc<Notify>,
+
+    /// How long a single order or confirmation phase waits for `2f+1`
+    /// replies before giving up on the round. Set from the
+    /// [`LoadGenConfig`] passed at construction.
+    phase_timeout: Duration,
+
+    /// Live counters worker tasks update as rounds complete.
+    stats: Arc<RunStats>,
+
+    /// Sinks the background [`StatusReporter`] fans each periodic sample
+    /// out to. Defaults to a single [`TracingStatusSink`]; push more (e.g.
+    /// a [`KafkaStatusSink`]) before calling `start()`.
+    pub status_sinks: Vec<Arc<dyn StatusSink>>,
+
+    /// How often the background reporter samples and reports `stats`.
+    pub status_display_interval: Duration,
 }
 
 // new -> ready -> start
@@ -208,23 +864,72 @@ impl FixedRateLoadGenerator {
         period_us: u64,
         network_clients: Vec<NetworkClient>,
         connections: usize,
-    ) -> Self {
+        config: LoadGenConfig,
+    ) -> Result<Self, Error> {
+        Self::new_for_multi_validator_with_sink(
+            transactions,
+            period_us,
+            network_clients,
+            connections,
+            config,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Same as [`Self::new_for_multi_validator`], but additionally streams
+    /// each completed round to `results_sink` (e.g. a [`KafkaResultsSink`])
+    /// as it happens, and, if `connectivity` is set, skips validators the
+    /// monitor currently considers unreachable.
+    ///
+    /// Returns an error rather than panicking if any transaction exceeds
+    /// `config.max_payload_size`, so one oversized transaction in an
+    /// otherwise-valid workload doesn't take down the whole benchmark
+    /// process.
+    pub async fn new_for_multi_validator_with_sink(
+        transactions: Vec<Bytes>,
+        period_us: u64,
+        network_clients: Vec<NetworkClient>,
+        connections: usize,
+        config: LoadGenConfig,
+        results_sink: Option<Arc<dyn ResultsSink>>,
+        connectivity: Option<Arc<ConnectivityMonitor>>,
+    ) -> Result<Self, Error> {
+        config.validate();
+        assert!(
+            transactions.len() % 2 == 0,
+            "transactions must contain an even number of entries (order+confirmation pairs)"
+        );
+        for tx in &transactions {
+            if tx.len() > config.max_payload_size {
+                return Err(anyhow!(
+                    "transaction of {} bytes exceeds configured max_payload_size of {} bytes",
+                    tx.len(),
+                    config.max_payload_size
+                ));
+            }
+        }
+
         let mut handles = vec![];
         let tick_notifier = Arc::new(Notify::new());
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let stats = Arc::new(RunStats::default());
 
-        let (result_chann_tx, results_chann_rx) = MpscChannel(transactions.len() * 2);
+        let (result_chann_tx, results_chann_rx) = MpscChannel(config.channel_capacity);
 
         let conn = connections;
-        // Spin up a bunch of worker tasks
-        // Give each task
-        // Step by 2*conn due to order+confirmation, with `conn` tcp connections
-        // Take up to 2*conn for each task
-        let num_chunks_per_task = conn * 2;
-        for tx_chunk in transactions[..].chunks(num_chunks_per_task) {
+        let chunk_size_per_task = config.chunk_size_per_task;
+        let phase_timeout = config.phase_timeout;
+        for tx_chunk in transactions[..].chunks(chunk_size_per_task) {
             let notif = tick_notifier.clone();
             let mut result_chann_tx = result_chann_tx.clone();
             let tx_chunk = tx_chunk.to_vec();
             let clients = network_clients.clone();
+            let results_sink = results_sink.clone();
+            let connectivity = connectivity.clone();
+            let stats = stats.clone();
+            let shutdown = shutdown.clone();
 
             let mut order_chunk = vec![];
             let mut conf_chunk = vec![];
@@ -242,6 +947,11 @@ impl FixedRateLoadGenerator {
                     &mut result_chann_tx,
                     clients,
                     conn,
+                    results_sink,
+                    connectivity,
+                    stats,
+                    phase_timeout,
+                    shutdown,
                 )
                 .await;
             }));
@@ -249,15 +959,21 @@ impl FixedRateLoadGenerator {
 
         drop(result_chann_tx);
 
-        Self {
+        Ok(Self {
             period_us,
             network_clients,
             transactions,
             connections,
             results_chann_rx,
             tick_notifier,
-            chunk_size_per_task: num_chunks_per_task,
-        }
+            chunk_size_per_task,
+            shutdown,
+            shutdown_notify: Arc::new(Notify::new()),
+            phase_timeout,
+            stats: stats.clone(),
+            status_sinks: vec![Arc::new(TracingStatusSink)],
+            status_display_interval: Duration::from_secs(5),
+        })
     }
 
     pub async fn new(
@@ -265,65 +981,186 @@ impl FixedRateLoadGenerator {
         period_us: u64,
         network_client: NetworkClient,
         connections: usize,
-    ) -> Self {
+        config: LoadGenConfig,
+    ) -> Result<Self, Error> {
+        Self::new_with_sink(
+            transactions,
+            period_us,
+            network_client,
+            connections,
+            config,
+            None,
+        )
+        .await
+    }
+
+    /// Same as [`Self::new`], but additionally streams each completed round
+    /// to `results_sink` as it happens.
+    ///
+    /// Returns an error rather than panicking if any transaction exceeds
+    /// `config.max_payload_size`, so one oversized transaction in an
+    /// otherwise-valid workload doesn't take down the whole benchmark
+    /// process.
+    pub async fn new_with_sink(
+        transactions: Vec<Bytes>,
+        period_us: u64,
+        network_client: NetworkClient,
+        connections: usize,
+        config: LoadGenConfig,
+        results_sink: Option<Arc<dyn ResultsSink>>,
+    ) -> Result<Self, Error> {
+        config.validate();
+        assert!(
+            transactions.len() % 2 == 0,
+            "transactions must contain an even number of entries (order+confirmation pairs)"
+        );
+        for tx in &transactions {
+            if tx.len() > config.max_payload_size {
+                return Err(anyhow!(
+                    "transaction of {} bytes exceeds configured max_payload_size of {} bytes",
+                    tx.len(),
+                    config.max_payload_size
+                ));
+            }
+        }
+
         let mut handles = vec![];
         let tick_notifier = Arc::new(Notify::new());
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let stats = Arc::new(RunStats::default());
 
-        let (result_chann_tx, results_chann_rx) = MpscChannel(transactions.len() * 2);
+        let (result_chann_tx, results_chann_rx) = MpscChannel(config.channel_capacity);
 
         let conn = connections;
-        // Spin up a bunch of worker tasks
-        // Give each task
-        // Step by 2*conn due to order+confirmation, with `conn` tcp connections
-        // Take up to 2*conn for each task
-        let num_chunks_per_task = conn * 2;
-        for tx_chunk in transactions[..].chunks(num_chunks_per_task) {
+        let chunk_size_per_task = config.chunk_size_per_task;
+        for tx_chunk in transactions[..].chunks(chunk_size_per_task) {
             let notif = tick_notifier.clone();
             let mut result_chann_tx = result_chann_tx.clone();
             let tx_chunk = tx_chunk.to_vec();
             let client = network_client.clone();
+            let results_sink = results_sink.clone();
+            let stats = stats.clone();
+            let shutdown = shutdown.clone();
 
             handles.push(tokio::spawn(async move {
-                send_tx_chunks_notif(notif, tx_chunk, &mut result_chann_tx, client, conn).await;
+                send_tx_chunks_notif(
+                    notif,
+                    tx_chunk,
+                    &mut result_chann_tx,
+                    client,
+                    conn,
+                    results_sink,
+                    stats,
+                    shutdown,
+                )
+                .await;
             }));
         }
 
         drop(result_chann_tx);
 
-        Self {
+        Ok(Self {
             period_us,
             network_clients: vec![network_client],
             transactions,
             connections,
             results_chann_rx,
             tick_notifier,
-            chunk_size_per_task: num_chunks_per_task,
-        }
+            chunk_size_per_task,
+            shutdown,
+            shutdown_notify: Arc::new(Notify::new()),
+            phase_timeout: config.phase_timeout,
+            stats: stats.clone(),
+            status_sinks: vec![Arc::new(TracingStatusSink)],
+            status_display_interval: Duration::from_secs(5),
+        })
+    }
+
+    /// Requests that the currently running (or about to run) `start()` stop
+    /// issuing new ticks. Already-dispatched quorum rounds are still awaited
+    /// so the returned [`LatencyReport`] remains consistent.
+    pub fn request_shutdown(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_waiters();
     }
 
-    pub async fn start(&mut self) -> Vec<u128> {
+    pub async fn start(&mut self) -> LatencyReport {
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                shutdown.store(true, Ordering::SeqCst);
+                shutdown_notify.notify_waiters();
+            }
+        });
+
+        let reporter = StatusReporter {
+            stats: self.stats.clone(),
+            sinks: self.status_sinks.clone(),
+            display_interval: self.status_display_interval,
+        };
+        let reporter_shutdown = Arc::new(AtomicBool::new(false));
+        tokio::spawn(reporter.run(reporter_shutdown.clone()));
+
         let mut interval = time::interval(Duration::from_micros(self.period_us));
         let mut count = 0;
         loop {
             tokio::select! {
                 _  = interval.tick() => {
+                    if self.shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
                     self.tick_notifier.notify_one();
                     count += self.chunk_size_per_task;
                     if count >= self.transactions.len() {
                         break;
                     }
                 }
+                _ = self.shutdown_notify.notified() => {
+                    // Raced against `interval.tick()` so Ctrl-C /
+                    // `request_shutdown()` is noticed immediately rather
+                    // than waiting out the rest of the current period.
+                    break;
+                }
             }
         }
-        let mut times = Vec::new();
-        while let Some(v) = time::timeout(Duration::from_secs(10), self.results_chann_rx.next())
+        // Signal the status reporter to stop now that the main loop has
+        // exited, whether that was a natural finish or a requested
+        // shutdown. On a requested shutdown, also wake any worker tasks
+        // still parked on `tick_notifier` waiting for a chunk that will now
+        // never be dispatched — each checks `shutdown` as soon as it wakes
+        // and returns without sending, instead of waiting forever. On a
+        // natural finish every worker task has already been given a tick,
+        // so `shutdown` stays unset and none of them skips its round.
+        reporter_shutdown.store(true, Ordering::SeqCst);
+        if self.shutdown.load(Ordering::SeqCst) {
+            self.tick_notifier.notify_waiters();
+        }
+
+        // From here on we no longer issue new ticks, but still drain
+        // whatever rounds were already dispatched so a shutdown mid-run
+        // yields a partial but internally consistent report rather than a
+        // truncated one. A round runs its order phase then its
+        // confirmation phase sequentially, each independently allowed up
+        // to `phase_timeout`, so a round that's mid-confirmation when the
+        // previous result arrived can legitimately take close to
+        // `2 * phase_timeout` to report in; sizing the drain gap off a
+        // single phase would give up on — and silently drop — that round.
+        let drain_timeout = self.phase_timeout * 2;
+        let mut order = LatencyHistogram::new();
+        let mut confirmation = LatencyHistogram::new();
+        while let Some(round) = time::timeout(drain_timeout, self.results_chann_rx.next())
             .await
             .unwrap_or(None)
         {
-            times.push(v);
+            order.merge(&round.order);
+            confirmation.merge(&round.confirmation);
         }
 
-        times
+        LatencyReport {
+            order: order.stats(),
+            confirmation: confirmation.stats(),
+        }
     }
 }
 
@@ -343,3 +1180,239 @@ pub async fn spawn_authority_server(
 pub fn calculate_throughput(num_items: usize, elapsed_time_us: u128) -> f64 {
     1_000_000.0 * num_items as f64 / elapsed_time_us as f64
 }
+
+/// Gas budget given to transactions that should execute successfully.
+/// Comfortably affordable against whatever balance the benchmark harness's
+/// genesis setup funds a generated account's gas object with.
+const WORKLOAD_GAS_BUDGET: u64 = 1_000;
+/// Gas budget given to transactions in `failure_fraction`: no real gas
+/// object can cover this, so execution deterministically reports
+/// `ExecutionStatus::Failure` on insufficient gas regardless of how the
+/// harness happens to fund accounts.
+const WORKLOAD_FAILING_GAS_BUDGET: u64 = u64::MAX;
+
+/// Configuration for [`generate_workload`]: a fully reproducible transaction
+/// workload, independent of any live validator state, so two runs (e.g.
+/// before/after a code change) are directly comparable.
+#[derive(Clone, Debug)]
+pub struct WorkloadConfig {
+    /// The same seed always yields byte-identical output.
+    pub seed: u64,
+    /// Number of order+confirmation pairs to generate.
+    pub tx_count: usize,
+    /// Fraction (`0.0..=1.0`) of generated transactions that are given an
+    /// unaffordable gas budget, so a run can exercise the
+    /// `ExecutionStatus::Failure` path that `check_transaction_response`
+    /// already special-cases.
+    pub failure_fraction: f64,
+}
+
+/// Deterministically generates a `config.tx_count`-long sequence of
+/// order+confirmation `Bytes` pairs, interleaved as `FixedRateLoadGenerator`
+/// expects (`[order0, conf0, order1, conf1, ...]`), using a seeded
+/// `ChaCha8Rng` to derive each transaction's sender keypair, recipient, and
+/// object references, so the same seed always signs byte-identical
+/// transactions.
+///
+/// Each pair is a single signed transfer `Transaction`, serialized twice.
+/// The confirmation slot cannot carry a genuine certificate here: that
+/// requires collecting `2f+1` validator signatures from a live order round,
+/// which an offline generator has no access to. Sending the same signed
+/// transaction to both phases keeps the payload shape `FixedRateLoadGenerator`
+/// already expects while still exercising real (de)serialization and
+/// execution, rather than the opaque marker-byte payloads this used to emit.
+pub fn generate_workload(config: &WorkloadConfig) -> Vec<Bytes> {
+    assert!(
+        (0.0..=1.0).contains(&config.failure_fraction),
+        "failure_fraction must be in 0.0..=1.0"
+    );
+
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+    let mut transactions = Vec::with_capacity(config.tx_count * 2);
+
+    for _ in 0..config.tx_count {
+        let should_fail = rng.gen::<f64>() < config.failure_fraction;
+        let tx_bytes = random_signed_transfer(&mut rng, should_fail);
+        transactions.push(tx_bytes.clone());
+        transactions.push(tx_bytes);
+    }
+
+    transactions
+}
+
+/// Builds a signed `TransferCoin` transaction between two freshly derived
+/// keypairs, transferring a freshly derived object with a freshly derived
+/// gas payment object. The object and gas-object IDs are only meaningful
+/// against a validator genesis that has pre-funded these deterministically
+/// derived addresses (that setup lives in whatever harness runs the
+/// benchmark, not in this generator); `should_fail` controls only the gas
+/// budget, so execution fails or succeeds independent of that funding.
+fn random_signed_transfer(rng: &mut ChaCha8Rng, should_fail: bool) -> Bytes {
+    let (sender, keypair) = get_key_pair_from_rng(rng);
+    let (recipient, _) = get_key_pair_from_rng(rng);
+
+    let object_ref = (
+        random_object_id(rng),
+        SequenceNumber::new(),
+        ObjectDigest::new(random_digest_bytes(rng)),
+    );
+    let gas_payment = (
+        random_object_id(rng),
+        SequenceNumber::new(),
+        ObjectDigest::new(random_digest_bytes(rng)),
+    );
+
+    let gas_budget = if should_fail {
+        WORKLOAD_FAILING_GAS_BUDGET
+    } else {
+        WORKLOAD_GAS_BUDGET
+    };
+
+    let kind = SingleTransactionKind::Transfer(TransferCoin {
+        recipient,
+        object_ref,
+    });
+    let data = TransactionData::new(kind, sender, gas_payment, gas_budget);
+    let signature = Signature::new(&data, &keypair);
+    let transaction = Transaction::new(data, signature);
+
+    Bytes::from(serialize_transaction(&transaction))
+}
+
+fn random_object_id(rng: &mut ChaCha8Rng) -> ObjectID {
+    let mut bytes = [0u8; ObjectID::LENGTH];
+    rng.fill(&mut bytes);
+    ObjectID::try_from(&bytes[..]).expect("ObjectID::LENGTH bytes always parse")
+}
+
+fn random_digest_bytes(rng: &mut ChaCha8Rng) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    rng.fill(&mut bytes);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_for_min_and_max_clamp_to_histogram_range() {
+        // Anything at or below HISTOGRAM_MIN_US lands in bucket 0.
+        assert_eq!(histogram_bucket_for(0), 0);
+        assert_eq!(histogram_bucket_for(HISTOGRAM_MIN_US as u128), 0);
+        // Anything at or above HISTOGRAM_MAX_US is folded into the top bucket.
+        let top = histogram_num_buckets() - 1;
+        assert_eq!(histogram_bucket_for(HISTOGRAM_MAX_US as u128), top);
+        assert_eq!(histogram_bucket_for(u128::MAX), top);
+    }
+
+    #[test]
+    fn bucket_boundaries_are_monotonically_increasing() {
+        // A larger value should never fall in an earlier bucket than a
+        // smaller one, and the upper bound of each bucket should exceed the
+        // upper bound of the one before it.
+        let mut last_bucket = 0;
+        let mut last_bound = 0;
+        for value_us in [10, 20, 50, 100, 1_000, 10_000, 100_000, 1_000_000] {
+            let bucket = histogram_bucket_for(value_us);
+            assert!(bucket >= last_bucket);
+            let bound = histogram_bucket_upper_bound_us(bucket);
+            assert!(bound > last_bound || bucket == last_bucket);
+            last_bucket = bucket;
+            last_bound = bound;
+        }
+    }
+
+    #[test]
+    fn record_and_percentile_known_distribution() {
+        let mut hist = LatencyHistogram::new();
+        for value_us in [10, 20, 30, 40, 100] {
+            hist.record(value_us);
+        }
+        let stats = hist.stats();
+        assert_eq!(stats.count, 5);
+        assert_eq!(stats.min_us, 10);
+        assert_eq!(stats.max_us, 100);
+        // p100 must always resolve to the bucket covering the max sample.
+        assert!(hist.percentile(100.0) >= 100);
+        // p50 (the 3rd of 5 ascending samples) must fall in a bucket
+        // covering 30us but stay below the bucket of the 100us max sample.
+        let p50 = hist.percentile(50.0);
+        assert!(p50 >= 30);
+        assert!(p50 < histogram_bucket_upper_bound_us(histogram_bucket_for(100)));
+    }
+
+    #[test]
+    fn percentile_of_empty_histogram_is_zero() {
+        let hist = LatencyHistogram::new();
+        assert_eq!(hist.percentile(50.0), 0);
+        assert_eq!(hist.percentile(99.9), 0);
+    }
+
+    #[test]
+    fn merge_is_associative_and_order_independent() {
+        let mut a = LatencyHistogram::new();
+        a.record(15);
+        a.record(5_000);
+        let mut b = LatencyHistogram::new();
+        b.record(250);
+        let mut c = LatencyHistogram::new();
+        c.record(90_000);
+
+        // (a merge b) merge c
+        let mut ab_then_c = a.clone();
+        ab_then_c.merge(&b);
+        ab_then_c.merge(&c);
+
+        // a merge (b merge c)
+        let mut bc = b.clone();
+        bc.merge(&c);
+        let mut a_then_bc = a.clone();
+        a_then_bc.merge(&bc);
+
+        assert_eq!(ab_then_c.count, a_then_bc.count);
+        assert_eq!(ab_then_c.sum_us, a_then_bc.sum_us);
+        assert_eq!(ab_then_c.min_us, a_then_bc.min_us);
+        assert_eq!(ab_then_c.max_us, a_then_bc.max_us);
+        assert_eq!(ab_then_c.buckets, a_then_bc.buckets);
+    }
+
+    #[test]
+    fn validate_accepts_default_config() {
+        LoadGenConfig::default().validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk_size_per_task must be even")]
+    fn validate_rejects_odd_chunk_size() {
+        let mut config = LoadGenConfig::default();
+        config.chunk_size_per_task = 3;
+        config.validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk_size_per_task must be greater than zero")]
+    fn validate_rejects_zero_chunk_size() {
+        let mut config = LoadGenConfig::default();
+        config.chunk_size_per_task = 0;
+        config.validate();
+    }
+
+    #[test]
+    fn generate_workload_is_deterministic_for_a_given_seed() {
+        let config = WorkloadConfig {
+            seed: 42,
+            tx_count: 4,
+            failure_fraction: 0.5,
+        };
+        let first = generate_workload(&config);
+        let second = generate_workload(&config);
+        assert_eq!(first, second);
+        // Each logical transaction occupies the order+confirmation pair of
+        // slots generate_workload documents.
+        assert_eq!(first.len(), config.tx_count * 2);
+        for pair in first.chunks(2) {
+            assert_eq!(pair[0], pair[1]);
+        }
+    }
+}